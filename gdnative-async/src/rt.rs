@@ -12,11 +12,23 @@ use gdnative_core::TRef;
 
 use crate::future;
 
+mod block_on;
+mod blocking;
 mod bridge;
+mod cancel;
+mod combinators;
+mod executor;
 mod func_state;
+mod rust_future;
 
 use func_state::FuncState;
 
+pub use block_on::block_on;
+pub use cancel::{Cancellable, CancellationToken, Cancelled};
+pub use combinators::{join, select};
+pub use executor::{install as install_executor, spawn_local};
+pub use rust_future::{spawn_rust_future, RustFutureHandle};
+
 static REGISTRATION: OnceCell<()> = OnceCell::new();
 
 #[derive(Debug, Error)]
@@ -34,6 +46,7 @@ impl InitError {
 /// Context for creating `yield`-like futures in async methods.
 pub struct Context {
     func_state: Instance<FuncState, Shared>,
+    cancel: CancellationToken,
     /// Remove Send and Sync
     _marker: PhantomData<*const ()>,
 }
@@ -42,10 +55,33 @@ impl Context {
     pub(crate) fn new() -> Self {
         Context {
             func_state: FuncState::new().into_shared(),
+            cancel: CancellationToken::new(),
             _marker: PhantomData,
         }
     }
 
+    /// Wraps `future` so it resolves with `Err(Cancelled)` once this context is
+    /// cancelled via [`cancel`](Self::cancel), dropping the wrapped future and
+    /// disconnecting any signal bridge it was awaiting.
+    pub fn cancellable<F>(&self, future: F) -> Cancellable<F>
+    where
+        F: std::future::Future,
+    {
+        Cancellable::new(future, self.cancel.clone())
+    }
+
+    /// Returns a clone of this context's cancellation token, so callers can cancel
+    /// the in-flight await from elsewhere (e.g. when the owning node is freed).
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancel.clone()
+    }
+
+    /// Cancels any [`cancellable`](Self::cancellable) future created from this
+    /// context. The next poll resolves with `Err(Cancelled)` rather than hanging.
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
     pub(crate) fn func_state(&self) -> Instance<FuncState, Shared> {
         self.func_state.clone()
     }
@@ -105,6 +141,73 @@ impl Context {
         bridge::SignalBridge::connect(obj.upcast(), signal, resume)?;
         Ok(future)
     }
+
+    /// Runs the CPU-bound closure `f` on a background thread pool and returns a
+    /// future that resolves with its result on this thread.
+    ///
+    /// The computation never blocks an idle frame, and its result is marshalled
+    /// back by a completion probe that the cooperative executor polls on each idle
+    /// frame — the worker thread never touches this `!Send` `Context`. This is the
+    /// ergonomic way to do `let result = ctx.spawn_blocking(|| expensive()).await;`.
+    ///
+    /// Because the result is delivered while the executor is pumped, the returned
+    /// future only resolves if the executor [`Driver`](executor::install) is
+    /// installed and pumping each idle frame; without it the future never completes,
+    /// even once the worker has finished.
+    pub fn spawn_blocking<F, T>(&self, f: F) -> future::Yield<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        blocking::spawn_blocking(f)
+    }
+
+    /// Returns a future that waits until the first of the given signals is emitted,
+    /// yielding the index of that signal within `signals` together with the arguments
+    /// it was emitted with.
+    ///
+    /// When one signal fires, the `Yield`s waiting on the others are dropped, which
+    /// frees their [`SignalBridge`](bridge::SignalBridge) instances and so
+    /// disconnects the remaining signals — a bridge holds its connection only while
+    /// its `Yield` is alive.
+    ///
+    /// This lets an async method wait on several sources at once — e.g. "resolve when
+    /// `timeout` OR `pressed` fires" — without hand-rolling a state machine.
+    ///
+    /// # Errors
+    ///
+    /// If connection to any of the signals failed.
+    pub fn any_signal<C>(
+        &self,
+        signals: &[(TRef<'_, C>, &str)],
+    ) -> Result<future::Yield<(usize, Vec<Variant>)>, GodotError>
+    where
+        C: SubClass<Object>,
+    {
+        // With no signals there is nothing that can ever resolve the future, so
+        // reject it rather than spawning a task that hangs forever.
+        if signals.is_empty() {
+            return Err(GodotError::InvalidParameter);
+        }
+
+        let mut sources = Vec::with_capacity(signals.len());
+        for (obj, signal) in signals {
+            let (source, resume) = future::make();
+            bridge::SignalBridge::connect(obj.upcast(), signal, resume)?;
+            sources.push(source);
+        }
+
+        // Route the first emission into a single shared resume slot. `select` drops
+        // the losing `Yield`s the instant the winner resolves, and each dropped
+        // `Yield` frees its bridge and disconnects the signal it was waiting on.
+        let (future, resume) = future::make();
+        spawn_local(async move {
+            let winner = combinators::select(sources).await;
+            resume.resume(winner);
+        });
+
+        Ok(future)
+    }
 }
 
 pub fn register_runtime(handle: &InitHandle) -> Result<(), InitError> {
@@ -112,7 +215,9 @@ pub fn register_runtime(handle: &InitHandle) -> Result<(), InitError> {
 
     REGISTRATION.get_or_init(|| {
         handle.add_class::<bridge::SignalBridge>();
+        handle.add_class::<executor::Driver>();
         handle.add_class::<func_state::FuncState>();
+        handle.add_class::<rust_future::RustFutureHandle>();
         called = true;
     });
 
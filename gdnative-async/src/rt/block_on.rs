@@ -0,0 +1,74 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context as TaskContext, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+/// Runs `future` to completion on the current thread, blocking until it resolves.
+///
+/// This stands up a minimal local executor with a parking-based waker — the
+/// thread is parked while the future is pending and unparked when it is woken —
+/// so it is the synchronous counterpart to [`spawn_local`](super::spawn_local)
+/// for call sites that are inherently synchronous (tool scripts, `_ready`
+/// initialization, export operations).
+///
+/// # Deadlocks
+///
+/// Because the waker parks the calling thread, calling `block_on` on the main
+/// thread will **deadlock** if the awaited future can only make progress via an
+/// idle-frame tick from the cooperative executor: the blocked main thread can
+/// never reach [`Driver::_process`](super::executor), so the future it is waiting
+/// on is never pumped. This includes
+/// [`Context::spawn_blocking`](super::Context::spawn_blocking), whose result is
+/// delivered on an idle-frame tick. Only block on futures that are driven from
+/// another thread, or call it off the main thread.
+pub fn block_on<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    let mut future = Box::pin(future);
+
+    let parker = Arc::new(Parker {
+        thread: thread::current(),
+        notified: AtomicBool::new(false),
+    });
+    let waker = Waker::from(Arc::clone(&parker));
+    let mut cx = TaskContext::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(value) => return value,
+            Poll::Pending => {
+                while !parker.notified.swap(false, Ordering::Acquire) {
+                    thread::park();
+                }
+            }
+        }
+    }
+}
+
+struct Parker {
+    thread: Thread,
+    notified: AtomicBool,
+}
+
+impl Wake for Parker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.notified.store(true, Ordering::Release);
+        self.thread.unpark();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::block_on;
+
+    #[test]
+    fn runs_future_to_completion() {
+        assert_eq!(block_on(async { 1 + 1 }), 2);
+    }
+}
@@ -0,0 +1,150 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use once_cell::sync::OnceCell;
+
+use crate::future;
+
+use super::executor;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Runs `f` on the shared background thread pool and returns a future that
+/// resolves with its result on the calling thread.
+///
+/// The heavy work runs off-thread, and only the `Send` result crosses back: it is
+/// stashed in a shared slot that the cooperative executor drains on its next idle
+/// frame, where the `!Send` resume slot is filled. The worker never touches any
+/// engine-bound state.
+pub(crate) fn spawn_blocking<F, T>(f: F) -> future::Yield<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (future, resume) = future::make::<T>();
+
+    let slot: Arc<Mutex<Option<T>>> = Arc::new(Mutex::new(None));
+    let done = Arc::new(AtomicBool::new(false));
+
+    {
+        let slot = Arc::clone(&slot);
+        let done = Arc::clone(&done);
+        pool().execute(move || {
+            let result = f();
+            *slot.lock().unwrap() = Some(result);
+            done.store(true, Ordering::Release);
+        });
+    }
+
+    // The completion runs on the origin thread, so it is free to hold the `!Send`
+    // resume slot; it only reads the `Send` result once the worker signals `done`.
+    let mut resume = Some(resume);
+    executor::register_blocking(move || {
+        if !done.load(Ordering::Acquire) {
+            return false;
+        }
+        if let Some(resume) = resume.take() {
+            let value = slot
+                .lock()
+                .unwrap()
+                .take()
+                .expect("result is set before `done` is signalled");
+            resume.resume(value);
+        }
+        true
+    });
+
+    future
+}
+
+/// A small internal thread pool backing [`spawn_blocking`].
+struct Pool {
+    sender: Sender<Job>,
+}
+
+impl Pool {
+    fn new() -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+            .max(1);
+
+        for _ in 0..workers {
+            let receiver = Arc::clone(&receiver);
+            thread::Builder::new()
+                .name("gdnative-async-blocking".into())
+                .spawn(move || loop {
+                    let job = receiver.lock().unwrap().recv();
+                    match job {
+                        Ok(job) => job(),
+                        Err(_) => break,
+                    }
+                })
+                .expect("failed to spawn blocking worker thread");
+        }
+
+        Pool { sender }
+    }
+
+    fn execute(&self, job: Job) {
+        // The receivers live for the lifetime of the process, so this only fails
+        // during shutdown, where dropping the job is fine.
+        let _ = self.sender.send(job);
+    }
+}
+
+fn pool() -> &'static Pool {
+    static INSTANCE: OnceCell<Pool> = OnceCell::new();
+    INSTANCE.get_or_init(Pool::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use super::super::executor::{register_blocking, run_once};
+    use super::pool;
+
+    #[test]
+    fn result_is_delivered_on_the_draining_thread() {
+        let slot: Arc<Mutex<Option<i32>>> = Arc::new(Mutex::new(None));
+        let done = Arc::new(AtomicBool::new(false));
+
+        {
+            let slot = Arc::clone(&slot);
+            let done = Arc::clone(&done);
+            pool().execute(Box::new(move || {
+                *slot.lock().unwrap() = Some(21 * 2);
+                done.store(true, Ordering::Release);
+            }));
+        }
+
+        // The `!Send` sink can only be filled on this (the draining) thread.
+        let delivered: Rc<Cell<Option<i32>>> = Rc::new(Cell::new(None));
+        let sink = Rc::clone(&delivered);
+        let mut slot = Some(slot);
+        register_blocking(move || {
+            if !done.load(Ordering::Acquire) {
+                return false;
+            }
+            let slot = slot.take().expect("probe runs once");
+            sink.set(slot.lock().unwrap().take());
+            true
+        });
+
+        // Pump the run-queue until the worker finishes and the probe delivers.
+        while delivered.get().is_none() {
+            run_once();
+            std::thread::yield_now();
+        }
+        assert_eq!(delivered.get(), Some(42));
+    }
+}
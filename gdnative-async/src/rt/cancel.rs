@@ -0,0 +1,186 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use thiserror::Error;
+
+const PENDING: usize = 0;
+const CANCELLED: usize = 1;
+
+/// Returned from a [`Cancellable`] future that was cancelled before it resolved.
+#[derive(Debug, Error)]
+#[error("the awaited future was cancelled")]
+pub struct Cancelled {
+    _private: (),
+}
+
+impl Cancelled {
+    fn new() -> Self {
+        Cancelled { _private: () }
+    }
+}
+
+/// A cloneable handle that can deterministically cancel an in-flight await.
+///
+/// The shared state is a small atomic machine with `PENDING` and `CANCELLED`
+/// bits, transitioned with acquire/release ordering so the resume callback and
+/// the poller can race safely. The token never latches on completion, so a single
+/// token stays usable across the sequential awaits made from one context. Clone it
+/// into a future with
+/// [`Context::cancellable`](super::Context::cancellable) and flip it from
+/// [`Context::cancel`](super::Context::cancel).
+#[derive(Clone)]
+pub struct CancellationToken {
+    shared: Arc<Shared>,
+}
+
+struct Shared {
+    state: AtomicUsize,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl CancellationToken {
+    pub(crate) fn new() -> Self {
+        CancellationToken {
+            shared: Arc::new(Shared {
+                state: AtomicUsize::new(PENDING),
+                waker: Mutex::new(None),
+            }),
+        }
+    }
+
+    /// Flips the state to `CANCELLED` and wakes the pending poll so it observes
+    /// the cancellation on its next tick.
+    ///
+    /// The waker slot is taken under the same lock [`register`](Self::register)
+    /// stores it through, so a `cancel` that races an in-flight poll either wakes
+    /// the registered waker or is observed by `register` — the wake is never lost.
+    pub fn cancel(&self) {
+        let waker = {
+            let mut slot = self.shared.waker.lock().unwrap();
+            if self
+                .shared
+                .state
+                .compare_exchange(PENDING, CANCELLED, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                slot.take()
+            } else {
+                None
+            }
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    /// Returns `true` once [`cancel`](Self::cancel) has been called on any clone.
+    pub fn is_cancelled(&self) -> bool {
+        self.shared.state.load(Ordering::Acquire) == CANCELLED
+    }
+
+    /// Stores the poll's waker for a later [`cancel`](Self::cancel).
+    ///
+    /// Returns `true` if cancellation was already observed under the lock, in which
+    /// case no wake will be delivered and the caller must resolve as cancelled.
+    fn register(&self, waker: &Waker) -> bool {
+        let mut slot = self.shared.waker.lock().unwrap();
+        if self.shared.state.load(Ordering::Acquire) == CANCELLED {
+            return true;
+        }
+        match &*slot {
+            Some(existing) if existing.will_wake(waker) => {}
+            _ => *slot = Some(waker.clone()),
+        }
+        false
+    }
+}
+
+/// Wraps a future so it resolves with `Err(Cancelled)` if its [`CancellationToken`]
+/// is flipped before it completes.
+///
+/// On cancellation the inner future is dropped immediately, disconnecting any
+/// [`SignalBridge`](super::bridge::SignalBridge) it was awaiting.
+pub struct Cancellable<F> {
+    inner: Option<Pin<Box<F>>>,
+    token: CancellationToken,
+}
+
+impl<F> Cancellable<F> {
+    pub(crate) fn new(future: F, token: CancellationToken) -> Self {
+        Cancellable {
+            inner: Some(Box::pin(future)),
+            token,
+        }
+    }
+}
+
+impl<F: Future> Future for Cancellable<F> {
+    type Output = Result<F::Output, Cancelled>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        if this.token.is_cancelled() {
+            // Drop the inner future so its bridge is disconnected right away.
+            this.inner = None;
+            return Poll::Ready(Err(Cancelled::new()));
+        }
+
+        let inner = match this.inner.as_mut() {
+            Some(inner) => inner,
+            None => return Poll::Ready(Err(Cancelled::new())),
+        };
+
+        match inner.as_mut().poll(cx) {
+            Poll::Ready(value) => {
+                this.inner = None;
+                Poll::Ready(Ok(value))
+            }
+            Poll::Pending => {
+                // Register the waker and re-check under the lock: a cancel that
+                // raced this poll is observed here rather than lost.
+                if this.token.register(cx.waker()) {
+                    this.inner = None;
+                    Poll::Ready(Err(Cancelled::new()))
+                } else {
+                    Poll::Pending
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::block_on::block_on;
+    use super::{Cancellable, CancellationToken};
+
+    #[test]
+    fn resolves_when_not_cancelled() {
+        let token = CancellationToken::new();
+        assert!(matches!(block_on(Cancellable::new(async { 7 }, token)), Ok(7)));
+    }
+
+    #[test]
+    fn cancel_yields_cancelled_error() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let result = block_on(Cancellable::new(std::future::pending::<()>(), token));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn token_stays_usable_after_a_completed_await() {
+        // A resolved await must not latch the token and disable later cancellation.
+        let token = CancellationToken::new();
+        assert!(block_on(Cancellable::new(async {}, token.clone())).is_ok());
+
+        token.cancel();
+        let result = block_on(Cancellable::new(std::future::pending::<()>(), token));
+        assert!(result.is_err());
+    }
+}
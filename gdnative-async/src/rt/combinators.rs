@@ -0,0 +1,135 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Future returned by [`join`].
+pub struct Join<F: Future> {
+    entries: Vec<Entry<F>>,
+}
+
+enum Entry<F: Future> {
+    Pending(Pin<Box<F>>),
+    Done(F::Output),
+    Taken,
+}
+
+/// Drives every future to completion, resolving to their outputs in input order.
+///
+/// The combined future polls each remaining child on every wake and only resolves
+/// once all of them have.
+pub fn join<I, F>(futures: I) -> Join<F>
+where
+    I: IntoIterator<Item = F>,
+    F: Future,
+{
+    Join {
+        entries: futures
+            .into_iter()
+            .map(|f| Entry::Pending(Box::pin(f)))
+            .collect(),
+    }
+}
+
+impl<F: Future> Future for Join<F> {
+    type Output = Vec<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        let mut all_done = true;
+        for entry in this.entries.iter_mut() {
+            if let Entry::Pending(future) = entry {
+                match future.as_mut().poll(cx) {
+                    Poll::Ready(value) => *entry = Entry::Done(value),
+                    Poll::Pending => all_done = false,
+                }
+            }
+        }
+
+        if all_done {
+            let values = this
+                .entries
+                .iter_mut()
+                .map(|entry| match std::mem::replace(entry, Entry::Taken) {
+                    Entry::Done(value) => value,
+                    _ => unreachable!("all entries are Done when the join resolves"),
+                })
+                .collect();
+            Poll::Ready(values)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Future returned by [`select`].
+pub struct Select<F: Future> {
+    futures: Vec<Pin<Box<F>>>,
+}
+
+/// Races every future, resolving with the index and output of the first to
+/// complete. The losers are dropped as soon as the winner fires.
+///
+/// An empty set of futures has no winner and so stays pending forever; callers
+/// that can pass an empty set should guard against it.
+pub fn select<I, F>(futures: I) -> Select<F>
+where
+    I: IntoIterator<Item = F>,
+    F: Future,
+{
+    Select {
+        futures: futures.into_iter().map(Box::pin).collect(),
+    }
+}
+
+impl<F: Future> Future for Select<F> {
+    type Output = (usize, F::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        let mut winner = None;
+        for (index, future) in this.futures.iter_mut().enumerate() {
+            if let Poll::Ready(value) = future.as_mut().poll(cx) {
+                winner = Some((index, value));
+                break;
+            }
+        }
+
+        match winner {
+            Some(winner) => {
+                // Drop the losers as soon as the winner fires.
+                this.futures.clear();
+                Poll::Ready(winner)
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{join, select};
+    use super::super::block_on::block_on;
+
+    #[test]
+    fn join_collects_results_in_order() {
+        let results = block_on(join((1..=3).map(|i| async move { i * 2 })));
+        assert_eq!(results, vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn select_resolves_with_first_ready() {
+        async fn value(ready: bool, v: i32) -> i32 {
+            if ready {
+                v
+            } else {
+                std::future::pending::<i32>().await
+            }
+        }
+
+        let (index, value) =
+            block_on(select(vec![value(false, 1), value(true, 2), value(false, 3)]));
+        assert_eq!((index, value), (1, 2));
+    }
+}
@@ -0,0 +1,229 @@
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context as TaskContext, Poll, RawWaker, RawWakerVTable, Waker};
+
+use gdnative_bindings::Node;
+use gdnative_core::nativescript::NativeClass;
+use gdnative_core::TRef;
+
+thread_local! {
+    static EXECUTOR: Rc<Executor> = Rc::new(Executor::new());
+    static BLOCKING: RefCell<Vec<Box<dyn FnMut() -> bool + 'static>>> = RefCell::new(Vec::new());
+}
+
+/// Spawns a future onto the current thread's cooperative executor.
+///
+/// The future is polled by the hidden [`Driver`] autoload on the first Godot
+/// idle frame after it is spawned or woken, and dropped as soon as it resolves. Because the executor is
+/// single-threaded and `!Send`, this may only be called from the thread that
+/// owns the engine objects the future touches.
+pub fn spawn_local<F>(future: F)
+where
+    F: Future<Output = ()> + 'static,
+{
+    EXECUTOR.with(|exec| exec.spawn(future));
+}
+
+/// Polls every task that became ready since the last tick.
+///
+/// Called from [`Driver::_process`] once per idle frame.
+pub(crate) fn run_once() {
+    drain_blocking();
+    EXECUTOR.with(|exec| exec.run_until_stalled());
+}
+
+/// Registers a completion probe polled once per idle frame, used to marshal
+/// `spawn_blocking` results back onto this thread. The probe returns `true` once
+/// it has delivered its result and should be dropped.
+pub(crate) fn register_blocking<P>(probe: P)
+where
+    P: FnMut() -> bool + 'static,
+{
+    BLOCKING.with(|blocking| blocking.borrow_mut().push(Box::new(probe)));
+}
+
+fn drain_blocking() {
+    BLOCKING.with(|blocking| {
+        let mut probes = blocking.borrow_mut();
+        let mut index = 0;
+        while index < probes.len() {
+            if probes[index]() {
+                probes.swap_remove(index);
+            } else {
+                index += 1;
+            }
+        }
+    });
+}
+
+/// The single-threaded run-queue executor.
+///
+/// A deque of ready [`Task`]s, each of which re-queues itself through its cached
+/// [`Waker`] when woken.
+struct Executor {
+    ready: RefCell<VecDeque<Rc<Task>>>,
+}
+
+impl Executor {
+    fn new() -> Self {
+        Executor {
+            ready: RefCell::new(VecDeque::new()),
+        }
+    }
+
+    fn spawn<F>(self: &Rc<Self>, future: F)
+    where
+        F: Future<Output = ()> + 'static,
+    {
+        let task = Task::new(Rc::clone(self), Box::pin(future));
+        self.enqueue(task);
+    }
+
+    fn enqueue(&self, task: Rc<Task>) {
+        if !task.is_queued.replace(true) {
+            self.ready.borrow_mut().push_back(task);
+        }
+    }
+
+    /// Drains the run-queue exactly once, polling each task that was ready at
+    /// the start of the tick. Tasks re-woken while this runs are left for the
+    /// next frame so a self-waking future can't starve the idle loop.
+    fn run_until_stalled(&self) {
+        let mut batch = std::mem::take(&mut *self.ready.borrow_mut());
+        while let Some(task) = batch.pop_front() {
+            task.is_queued.set(false);
+            task.poll();
+        }
+    }
+}
+
+/// A spawned future together with the bookkeeping needed to re-queue it.
+struct Task {
+    future: RefCell<Pin<Box<dyn Future<Output = ()> + 'static>>>,
+    /// Cached once at creation so polling never re-allocates a waker.
+    waker: RefCell<Option<Waker>>,
+    /// Set while the task sits in the run-queue, to dedupe re-wakes.
+    is_queued: Cell<bool>,
+    executor: Rc<Executor>,
+}
+
+impl Task {
+    fn new(
+        executor: Rc<Executor>,
+        future: Pin<Box<dyn Future<Output = ()> + 'static>>,
+    ) -> Rc<Self> {
+        let task = Rc::new(Task {
+            future: RefCell::new(future),
+            waker: RefCell::new(None),
+            is_queued: Cell::new(false),
+            executor,
+        });
+
+        // SAFETY: `raw_waker` upholds the `RawWakerVTable` contract, and the
+        // pointer is a valid `Rc<Task>` obtained via `Rc::into_raw`.
+        let waker = unsafe { Waker::from_raw(raw_waker(Rc::clone(&task))) };
+        *task.waker.borrow_mut() = Some(waker);
+        task
+    }
+
+    fn poll(self: &Rc<Self>) {
+        // The cached waker is cleared once the task resolves; a stale wake of a
+        // finished task is then a no-op rather than a re-poll.
+        let waker = match self.waker.borrow().clone() {
+            Some(waker) => waker,
+            None => return,
+        };
+        let mut cx = TaskContext::from_waker(&waker);
+
+        let mut future = self.future.borrow_mut();
+        if let Poll::Ready(()) = future.as_mut().poll(&mut cx) {
+            drop(future);
+            // Drop the cached waker to break the `Rc` cycle between the task and
+            // its own waker, so the task and its future can be freed.
+            *self.waker.borrow_mut() = None;
+        }
+    }
+}
+
+static VTABLE: RawWakerVTable =
+    RawWakerVTable::new(clone_waker, wake, wake_by_ref, drop_waker);
+
+fn raw_waker(task: Rc<Task>) -> RawWaker {
+    RawWaker::new(Rc::into_raw(task) as *const (), &VTABLE)
+}
+
+unsafe fn clone_waker(ptr: *const ()) -> RawWaker {
+    let task = Rc::from_raw(ptr as *const Task);
+    let clone = Rc::clone(&task);
+    std::mem::forget(task);
+    raw_waker(clone)
+}
+
+unsafe fn wake(ptr: *const ()) {
+    let task = Rc::from_raw(ptr as *const Task);
+    task.executor.enqueue(Rc::clone(&task));
+}
+
+unsafe fn wake_by_ref(ptr: *const ()) {
+    let task = Rc::from_raw(ptr as *const Task);
+    task.executor.enqueue(Rc::clone(&task));
+    std::mem::forget(task);
+}
+
+unsafe fn drop_waker(ptr: *const ()) {
+    drop(Rc::from_raw(ptr as *const Task));
+}
+
+/// Node that pumps the cooperative executor once per idle frame.
+///
+/// Registered in [`register_runtime`](super::register_runtime). Either register it
+/// as an autoload singleton from the Godot project, or install it from Rust with
+/// [`install`] so `spawn_local` futures make progress without a GDScript driver.
+#[derive(NativeClass)]
+#[inherit(Node)]
+pub(crate) struct Driver;
+
+#[methods]
+impl Driver {
+    fn new(_owner: &Node) -> Self {
+        Driver
+    }
+
+    #[export]
+    fn _process(&self, _owner: &Node, _delta: f64) {
+        run_once();
+    }
+}
+
+/// Installs the executor [`Driver`] as a child of `root`, so the cooperative
+/// executor is pumped every idle frame for the lifetime of that node.
+///
+/// Call this once from Rust (e.g. on the autoload/root node in `_ready`) if you
+/// are not registering the driver as a Godot autoload singleton yourself.
+pub fn install(root: TRef<'_, Node>) {
+    let driver = Driver::new_instance().into_base();
+    root.add_child(driver, false);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spawn_local_runs_on_tick() {
+        let flag = Rc::new(Cell::new(false));
+
+        let inner = Rc::clone(&flag);
+        spawn_local(async move {
+            inner.set(true);
+        });
+
+        // Nothing runs until the driver pumps the executor.
+        assert!(!flag.get());
+        run_once();
+        assert!(flag.get());
+    }
+}
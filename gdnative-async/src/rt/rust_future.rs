@@ -0,0 +1,217 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context as TaskContext, Poll, RawWaker, RawWakerVTable, Waker};
+
+use gdnative_bindings::{FuncRef, Reference};
+use gdnative_core::core_types::{Variant, VariantArray};
+use gdnative_core::nativescript::{Instance, NativeClass};
+use gdnative_core::object::Ref;
+use gdnative_core::thread_access::Shared;
+
+/// Result of [`RustFutureHandle::poll`].
+///
+/// `Pending` means the caller should poll again once the continuation fires;
+/// `Ready` means the result is available via [`RustFutureHandle::take_result`].
+#[repr(i64)]
+pub enum PollState {
+    Pending = 0,
+    Ready = 1,
+}
+
+/// A poll-driven view of a spawned Rust future that can be driven from GDScript.
+///
+/// Unlike [`FuncState`](super::func_state::FuncState), which emits a `resumable`
+/// signal for GDScript to resume, this handle is driven by a manual loop: register
+/// a continuation `FuncRef` with [`set_continuation`](Self::set_continuation), then
+/// call [`poll`](Self::poll) repeatedly. Whenever the future can make progress again
+/// its waker invokes the continuation so the caller knows to poll once more.
+#[derive(NativeClass)]
+#[inherit(Reference)]
+#[no_constructor]
+pub struct RustFutureHandle {
+    inner: Rc<Inner>,
+}
+
+struct Inner {
+    future: RefCell<Option<Pin<Box<dyn Future<Output = Variant> + 'static>>>>,
+    result: RefCell<Option<Variant>>,
+    continuation: RefCell<Option<Ref<FuncRef, Shared>>>,
+}
+
+/// Spawns `future` behind a [`RustFutureHandle`] instance that GDScript can poll
+/// to completion and then read the result from.
+pub fn spawn_rust_future<F>(future: F) -> Instance<RustFutureHandle, Shared>
+where
+    F: Future<Output = Variant> + 'static,
+{
+    let handle = RustFutureHandle {
+        inner: Rc::new(Inner {
+            future: RefCell::new(Some(Box::pin(future))),
+            result: RefCell::new(None),
+            continuation: RefCell::new(None),
+        }),
+    };
+    Instance::emplace(handle).into_shared()
+}
+
+#[methods]
+impl RustFutureHandle {
+    /// Registers the continuation `FuncRef` that is invoked whenever the future
+    /// becomes pollable again. It only needs to be set once.
+    #[export]
+    fn set_continuation(&self, _owner: &Reference, continuation: Ref<FuncRef, Shared>) {
+        *self.inner.continuation.borrow_mut() = Some(continuation);
+    }
+
+    /// Polls the future once, returning [`PollState::Pending`] or
+    /// [`PollState::Ready`] as an integer. On `Ready` the result can be taken with
+    /// [`take_result`](Self::take_result).
+    #[export]
+    fn poll(&self, _owner: &Reference) -> i64 {
+        let state = self.inner.poll();
+        state as i64
+    }
+
+    /// Takes the resolved value, or `Nil` if the future has not resolved yet (or the
+    /// result has already been taken).
+    #[export]
+    fn take_result(&self, _owner: &Reference) -> Variant {
+        self.inner
+            .result
+            .borrow_mut()
+            .take()
+            .unwrap_or_else(Variant::new)
+    }
+}
+
+impl Inner {
+    fn poll(self: &Rc<Self>) -> PollState {
+        if self.result.borrow().is_some() {
+            return PollState::Ready;
+        }
+
+        let mut future = match self.future.try_borrow_mut() {
+            Ok(future) => future,
+            // Re-entrant poll from the continuation: report pending and let the
+            // outer poll make progress.
+            Err(_) => return PollState::Pending,
+        };
+
+        let pinned = match future.as_mut() {
+            Some(pinned) => pinned,
+            None => return PollState::Ready,
+        };
+
+        // SAFETY: `raw_waker` upholds the `RawWakerVTable` contract over a valid
+        // `Rc<Inner>` obtained via `Rc::into_raw`.
+        let waker = unsafe { Waker::from_raw(raw_waker(Rc::clone(self))) };
+        let mut cx = TaskContext::from_waker(&waker);
+
+        match pinned.as_mut().poll(&mut cx) {
+            Poll::Ready(value) => {
+                *future = None;
+                *self.result.borrow_mut() = Some(value);
+                PollState::Ready
+            }
+            Poll::Pending => PollState::Pending,
+        }
+    }
+
+    /// Invoked from the waker: fires the continuation so the caller polls again.
+    fn wake(&self) {
+        if let Some(continuation) = self.continuation.borrow().as_ref() {
+            // SAFETY: the continuation is owned and invoked on the origin thread, in
+            // line with the global thread-safety assumptions.
+            let continuation = unsafe { continuation.assume_safe() };
+            continuation.call_func(VariantArray::new().into_shared());
+        }
+    }
+}
+
+static VTABLE: RawWakerVTable =
+    RawWakerVTable::new(clone_waker, wake, wake_by_ref, drop_waker);
+
+fn raw_waker(inner: Rc<Inner>) -> RawWaker {
+    RawWaker::new(Rc::into_raw(inner) as *const (), &VTABLE)
+}
+
+unsafe fn clone_waker(ptr: *const ()) -> RawWaker {
+    let inner = Rc::from_raw(ptr as *const Inner);
+    let clone = Rc::clone(&inner);
+    std::mem::forget(inner);
+    raw_waker(clone)
+}
+
+unsafe fn wake(ptr: *const ()) {
+    let inner = Rc::from_raw(ptr as *const Inner);
+    inner.wake();
+}
+
+unsafe fn wake_by_ref(ptr: *const ()) {
+    let inner = Rc::from_raw(ptr as *const Inner);
+    inner.wake();
+    std::mem::forget(inner);
+}
+
+unsafe fn drop_waker(ptr: *const ()) {
+    drop(Rc::from_raw(ptr as *const Inner));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::future::poll_fn;
+    use std::rc::Rc;
+    use std::task::Poll;
+
+    use gdnative_core::core_types::Variant;
+
+    use super::{Inner, PollState};
+
+    fn inner_with<F>(future: F) -> Rc<Inner>
+    where
+        F: std::future::Future<Output = Variant> + 'static,
+    {
+        Rc::new(Inner {
+            future: RefCell::new(Some(Box::pin(future))),
+            result: RefCell::new(None),
+            continuation: RefCell::new(None),
+        })
+    }
+
+    #[test]
+    fn pending_then_ready_then_take_result() {
+        let ready = Rc::new(Cell::new(false));
+
+        let flag = Rc::clone(&ready);
+        let inner = inner_with(poll_fn(move |_cx| {
+            if flag.get() {
+                Poll::Ready(Variant::from_i64(42))
+            } else {
+                Poll::Pending
+            }
+        }));
+
+        assert!(matches!(inner.poll(), PollState::Pending));
+
+        // In the FFI loop the waker fires the continuation here; with none set this
+        // is a no-op, and the caller polls again once the future can progress.
+        ready.set(true);
+        inner.wake();
+
+        assert!(matches!(inner.poll(), PollState::Ready));
+        assert_eq!(inner.result.borrow_mut().take(), Some(Variant::from_i64(42)));
+    }
+
+    #[test]
+    fn reentrant_poll_reports_pending() {
+        let inner = inner_with(async { Variant::from_i64(1) });
+
+        // A poll that re-enters while the future is already borrowed must not panic;
+        // it reports pending and lets the outer poll make progress.
+        let _guard = inner.future.borrow_mut();
+        assert!(matches!(inner.poll(), PollState::Pending));
+    }
+}